@@ -0,0 +1,5 @@
+mod promote_chat_member;
+mod restrict_chat_member;
+
+pub use self::promote_chat_member::*;
+pub use self::restrict_chat_member::*;
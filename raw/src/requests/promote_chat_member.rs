@@ -0,0 +1,151 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to promote or demote a user in a supergroup or a channel.
+///
+/// The bot must be an administrator in the chat for this to work and must
+/// have the appropriate admin rights. Pass `false` for all boolean
+/// parameters to demote a user.
+#[derive(Debug, Clone, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct PromoteChatMember {
+    chat_id: ChatRef,
+    user_id: UserId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_change_info: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_post_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_edit_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_delete_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_invite_users: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_restrict_members: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_pin_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_promote_members: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_manage_chat: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_post_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_edit_stories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    can_delete_stories: Option<bool>,
+}
+
+impl Request for PromoteChatMember {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        self.into_request("promoteChatMember")
+    }
+}
+
+impl PromoteChatMember {
+    pub fn new<C, U>(chat: C, user: U) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+            can_change_info: None,
+            can_post_messages: None,
+            can_edit_messages: None,
+            can_delete_messages: None,
+            can_invite_users: None,
+            can_restrict_members: None,
+            can_pin_messages: None,
+            can_promote_members: None,
+            can_manage_chat: None,
+            can_post_stories: None,
+            can_edit_stories: None,
+            can_delete_stories: None,
+        }
+    }
+
+    pub fn can_change_info(&mut self, can_change_info: bool) -> &mut Self {
+        self.can_change_info = Some(can_change_info);
+        self
+    }
+
+    pub fn can_post_messages(&mut self, can_post_messages: bool) -> &mut Self {
+        self.can_post_messages = Some(can_post_messages);
+        self
+    }
+
+    pub fn can_edit_messages(&mut self, can_edit_messages: bool) -> &mut Self {
+        self.can_edit_messages = Some(can_edit_messages);
+        self
+    }
+
+    pub fn can_delete_messages(&mut self, can_delete_messages: bool) -> &mut Self {
+        self.can_delete_messages = Some(can_delete_messages);
+        self
+    }
+
+    pub fn can_invite_users(&mut self, can_invite_users: bool) -> &mut Self {
+        self.can_invite_users = Some(can_invite_users);
+        self
+    }
+
+    pub fn can_restrict_members(&mut self, can_restrict_members: bool) -> &mut Self {
+        self.can_restrict_members = Some(can_restrict_members);
+        self
+    }
+
+    pub fn can_pin_messages(&mut self, can_pin_messages: bool) -> &mut Self {
+        self.can_pin_messages = Some(can_pin_messages);
+        self
+    }
+
+    pub fn can_promote_members(&mut self, can_promote_members: bool) -> &mut Self {
+        self.can_promote_members = Some(can_promote_members);
+        self
+    }
+
+    pub fn can_manage_chat(&mut self, can_manage_chat: bool) -> &mut Self {
+        self.can_manage_chat = Some(can_manage_chat);
+        self
+    }
+
+    pub fn can_post_stories(&mut self, can_post_stories: bool) -> &mut Self {
+        self.can_post_stories = Some(can_post_stories);
+        self
+    }
+
+    pub fn can_edit_stories(&mut self, can_edit_stories: bool) -> &mut Self {
+        self.can_edit_stories = Some(can_edit_stories);
+        self
+    }
+
+    pub fn can_delete_stories(&mut self, can_delete_stories: bool) -> &mut Self {
+        self.can_delete_stories = Some(can_delete_stories);
+        self
+    }
+}
+
+/// Promote or demote a chat member, e.g. `chat.promote_chat_member(&user)`.
+pub trait CanPromoteChatMemberForChat {
+    fn promote_chat_member<U>(&self, user: U) -> PromoteChatMember
+    where
+        U: ToUserId;
+}
+
+impl<C> CanPromoteChatMemberForChat for C
+where
+    C: ToChatRef,
+{
+    fn promote_chat_member<U>(&self, user: U) -> PromoteChatMember
+    where
+        U: ToUserId,
+    {
+        PromoteChatMember::new(self, user)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::requests::*;
+use crate::types::*;
+
+/// Use this method to restrict a user in a supergroup.
+///
+/// The bot must be an administrator in the supergroup for this to work and
+/// must have the `can_restrict_members` admin right. Pass `ChatPermissions`
+/// with all fields set to `true` to lift restrictions from a user.
+#[derive(Debug, Clone, Serialize)]
+#[must_use = "requests do nothing unless sent"]
+pub struct RestrictChatMember {
+    chat_id: ChatRef,
+    user_id: UserId,
+    permissions: ChatPermissions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    until_date: Option<Integer>,
+}
+
+impl Request for RestrictChatMember {
+    type Type = JsonRequestType<Self>;
+    type Response = JsonTrueToUnitResponse;
+
+    fn serialize(&self) -> Result<HttpRequest, Error> {
+        self.into_request("restrictChatMember")
+    }
+}
+
+impl RestrictChatMember {
+    pub fn new<C, U>(chat: C, user: U, permissions: ChatPermissions) -> Self
+    where
+        C: ToChatRef,
+        U: ToUserId,
+    {
+        Self {
+            chat_id: chat.to_chat_ref(),
+            user_id: user.to_user_id(),
+            permissions,
+            until_date: None,
+        }
+    }
+
+    /// Date when restrictions will be lifted for the user, unix time. If the
+    /// user is restricted for more than 366 days or less than 30 seconds
+    /// from the current time, they are considered restricted forever.
+    pub fn until_date(&mut self, until_date: Integer) -> &mut Self {
+        self.until_date = Some(until_date);
+        self
+    }
+}
+
+/// Restrict a chat member, e.g. `chat.restrict_chat_member(&user, permissions)`.
+pub trait CanRestrictChatMemberForChat {
+    fn restrict_chat_member<U>(&self, user: U, permissions: ChatPermissions) -> RestrictChatMember
+    where
+        U: ToUserId;
+}
+
+impl<C> CanRestrictChatMemberForChat for C
+where
+    C: ToChatRef,
+{
+    fn restrict_chat_member<U>(&self, user: U, permissions: ChatPermissions) -> RestrictChatMember
+    where
+        U: ToUserId,
+    {
+        RestrictChatMember::new(self, user, permissions)
+    }
+}
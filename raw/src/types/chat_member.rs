@@ -1,53 +1,228 @@
-use std::fmt;
-
-use serde::de;
-use serde::de::{Deserialize, Deserializer, Visitor};
+use serde::de::{self, Deserialize, Deserializer};
 
 use crate::types::*;
 
-/// The member's status in the chat
+/// The member's status in the chat and the status-specific data that comes
+/// with it.
+///
+/// This is deserialized from Telegram's `status` field together with
+/// whichever other fields are meaningful for that status, so e.g. a plain
+/// `Member` can never accidentally carry administrator rights. Fields that
+/// Telegram leaves out for a status are `None`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
-pub enum ChatMemberStatus {
-    Creator,
-    Administrator,
+pub enum ChatMemberKind {
+    Owner {
+        ///Custom title for this user
+        custom_title: Option<String>,
+        ///True, if the user's presence in the chat is hidden
+        is_anonymous: Option<bool>,
+    },
+    Administrator {
+        ///Custom title for this user
+        custom_title: Option<String>,
+        ///True, if the user's presence in the chat is hidden
+        is_anonymous: Option<bool>,
+        ///True, if the bot is allowed to edit administrator privileges of that user
+        can_be_edited: Option<bool>,
+        ///True, if the administrator can access the chat event log, chat statistics, message statistics in channels, see channel members, see anonymous administrators in supergroups and ignore slow mode, implies other privileges
+        can_manage_chat: Option<bool>,
+        ///True, if the administrator can change the chat title, photo and other settings
+        can_change_info: Option<bool>,
+        ///True, if the administrator can post in the channel, channels only
+        can_post_messages: Option<bool>,
+        ///True, if the administrator can edit messages of other users and can pin messages, channels only
+        can_edit_messages: Option<bool>,
+        ///True, if the administrator can delete messages of other users
+        can_delete_messages: Option<bool>,
+        ///True, if the administrator can manage voice chats
+        can_manage_voice_chats: Option<bool>,
+        ///True, if the administrator can invite new users to the chat
+        can_invite_users: Option<bool>,
+        ///True, if the administrator can restrict, ban or unban chat members
+        can_restrict_members: Option<bool>,
+        ///True, if the administrator can pin messages, supergroups only
+        can_pin_messages: Option<bool>,
+        ///True, if the administrator can post stories to the chat
+        can_post_stories: Option<bool>,
+        ///True, if the administrator can edit stories posted by other users
+        can_edit_stories: Option<bool>,
+        ///True, if the administrator can delete stories posted by other users
+        can_delete_stories: Option<bool>,
+        ///True, if the administrator can add new administrators with a subset of his own privileges or demote administrators that he has promoted, directly or indirectly (promoted by administrators that were appointed by the user)
+        can_promote_members: Option<bool>,
+    },
     Member,
+    Restricted {
+        ///Date when restrictions will be lifted for this user, unix time
+        until_date: Option<Integer>,
+        ///True, if the user can send text messages, contacts, locations and venues
+        can_send_messages: Option<bool>,
+        ///True, if the user can send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages
+        can_send_media_messages: Option<bool>,
+        ///True, if the user can send polls, implies can_send_messages
+        can_send_polls: Option<bool>,
+        ///True, if the user can send animations, games, stickers and use inline bots, implies can_send_media_messages
+        can_send_other_messages: Option<bool>,
+        ///True, if user may add web page previews to his messages, implies can_send_media_messages
+        can_add_web_page_previews: Option<bool>,
+        ///True, if the user is allowed to invite new users to the chat
+        can_invite_users: Option<bool>,
+        ///True, if the user is allowed to change the chat title, photo and other settings
+        can_change_info: Option<bool>,
+        ///True, if the user is allowed to pin messages, supergroups only
+        can_pin_messages: Option<bool>,
+    },
     Left,
-    Kicked,
+    Banned {
+        ///Date when restrictions will be lifted for this user, unix time
+        until_date: Option<Integer>,
+    },
+    /// Catch-all for any `status` Telegram sends that isn't one of the
+    /// above, carrying the raw status string along for logging/forward-compat.
     #[doc(hidden)]
     Unknown(String),
 }
 
-impl<'de> Deserialize<'de> for ChatMemberStatus {
-    fn deserialize<D>(deserializer: D) -> Result<ChatMemberStatus, D::Error>
+impl<'de> Deserialize<'de> for ChatMemberKind {
+    fn deserialize<D>(deserializer: D) -> Result<ChatMemberKind, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct ChatMemberStatusVisitor;
-        use self::ChatMemberStatus::*;
+        #[derive(Deserialize)]
+        struct Tagged {
+            status: String,
+            #[serde(flatten)]
+            rest: serde_json::Value,
+        }
 
-        impl<'de> Visitor<'de> for ChatMemberStatusVisitor {
-            type Value = ChatMemberStatus;
+        #[derive(Deserialize)]
+        struct OwnerFields {
+            custom_title: Option<String>,
+            is_anonymous: Option<bool>,
+        }
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("creator | administrator | member | left | kicked")
-            }
+        #[derive(Deserialize)]
+        struct AdministratorFields {
+            custom_title: Option<String>,
+            is_anonymous: Option<bool>,
+            can_be_edited: Option<bool>,
+            can_manage_chat: Option<bool>,
+            can_change_info: Option<bool>,
+            can_post_messages: Option<bool>,
+            can_edit_messages: Option<bool>,
+            can_delete_messages: Option<bool>,
+            can_manage_voice_chats: Option<bool>,
+            can_invite_users: Option<bool>,
+            can_restrict_members: Option<bool>,
+            can_pin_messages: Option<bool>,
+            can_post_stories: Option<bool>,
+            can_edit_stories: Option<bool>,
+            can_delete_stories: Option<bool>,
+            can_promote_members: Option<bool>,
+        }
 
-            fn visit_str<E>(self, value: &str) -> Result<ChatMemberStatus, E>
-            where
-                E: de::Error,
-            {
-                Ok(match value {
-                    "creator" => Creator,
-                    "administrator" => Administrator,
-                    "member" => Member,
-                    "left" => Left,
-                    "kicked" => Kicked,
-                    _unknown => Unknown(value.to_string()),
-                })
-            }
+        #[derive(Deserialize)]
+        struct RestrictedFields {
+            until_date: Option<Integer>,
+            can_send_messages: Option<bool>,
+            can_send_media_messages: Option<bool>,
+            can_send_polls: Option<bool>,
+            can_send_other_messages: Option<bool>,
+            can_add_web_page_previews: Option<bool>,
+            can_invite_users: Option<bool>,
+            can_change_info: Option<bool>,
+            can_pin_messages: Option<bool>,
         }
 
-        deserializer.deserialize_str(ChatMemberStatusVisitor)
+        #[derive(Deserialize)]
+        struct BannedFields {
+            until_date: Option<Integer>,
+        }
+
+        let Tagged { status, rest } = Tagged::deserialize(deserializer)?;
+
+        Ok(match status.as_str() {
+            "creator" => {
+                let OwnerFields {
+                    custom_title,
+                    is_anonymous,
+                } = serde_json::from_value(rest).map_err(de::Error::custom)?;
+                ChatMemberKind::Owner {
+                    custom_title,
+                    is_anonymous,
+                }
+            }
+            "administrator" => {
+                let AdministratorFields {
+                    custom_title,
+                    is_anonymous,
+                    can_be_edited,
+                    can_manage_chat,
+                    can_change_info,
+                    can_post_messages,
+                    can_edit_messages,
+                    can_delete_messages,
+                    can_manage_voice_chats,
+                    can_invite_users,
+                    can_restrict_members,
+                    can_pin_messages,
+                    can_post_stories,
+                    can_edit_stories,
+                    can_delete_stories,
+                    can_promote_members,
+                } = serde_json::from_value(rest).map_err(de::Error::custom)?;
+                ChatMemberKind::Administrator {
+                    custom_title,
+                    is_anonymous,
+                    can_be_edited,
+                    can_manage_chat,
+                    can_change_info,
+                    can_post_messages,
+                    can_edit_messages,
+                    can_delete_messages,
+                    can_manage_voice_chats,
+                    can_invite_users,
+                    can_restrict_members,
+                    can_pin_messages,
+                    can_post_stories,
+                    can_edit_stories,
+                    can_delete_stories,
+                    can_promote_members,
+                }
+            }
+            "member" => ChatMemberKind::Member,
+            "restricted" => {
+                let RestrictedFields {
+                    until_date,
+                    can_send_messages,
+                    can_send_media_messages,
+                    can_send_polls,
+                    can_send_other_messages,
+                    can_add_web_page_previews,
+                    can_invite_users,
+                    can_change_info,
+                    can_pin_messages,
+                } = serde_json::from_value(rest).map_err(de::Error::custom)?;
+                ChatMemberKind::Restricted {
+                    until_date,
+                    can_send_messages,
+                    can_send_media_messages,
+                    can_send_polls,
+                    can_send_other_messages,
+                    can_add_web_page_previews,
+                    can_invite_users,
+                    can_change_info,
+                    can_pin_messages,
+                }
+            }
+            "left" => ChatMemberKind::Left,
+            "kicked" => {
+                let BannedFields { until_date } =
+                    serde_json::from_value(rest).map_err(de::Error::custom)?;
+                ChatMemberKind::Banned { until_date }
+            }
+            other => ChatMemberKind::Unknown(other.to_string()),
+        })
     }
 }
 
@@ -56,36 +231,169 @@ impl<'de> Deserialize<'de> for ChatMemberStatus {
 pub struct ChatMember {
     /// Information about the user.
     pub user: User,
-    /// The member's status in the chat.
-    pub status: ChatMemberStatus,
-    ///Optional. Restricted and kicked only. Date when restrictions will be lifted for this user, unix time
-    pub until_date: Option<Integer>,
-    ///Optional. Administrators only. True, if the bot is allowed to edit administrator privileges of that user
-    pub can_be_edited: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can change the chat title, photo and other settings
-    pub can_change_info: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can post in the channel, channels only
-    pub can_post_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can edit messages of other users and can pin messages, channels only
-    pub can_edit_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can delete messages of other users
-    pub can_delete_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can invite new users to the chat
-    pub can_invite_users: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can restrict, ban or unban chat members
-    pub can_restrict_members: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can pin messages, supergroups only
-    pub can_pin_messages: Option<bool>,
-    ///Optional. Administrators only. True, if the administrator can add new administrators with a subset of his own privileges or demote administrators that he has promoted, directly or indirectly (promoted by administrators that were appointed by the user)
-    pub can_promote_members: Option<bool>,
-    ///Optional. Restricted only. True, if the user can send text messages, contacts, locations and venues
+    /// The member's status in the chat, together with its status-specific data.
+    #[serde(flatten)]
+    pub kind: ChatMemberKind,
+}
+
+/// Describes the permissions a non-administrator user has in a chat.
+///
+/// This is Telegram's shared permission set: it's used both as a chat's
+/// default permissions (`Chat::permissions`) and as the body of
+/// `restrictChatMember`. The `Restricted` variant of [`ChatMemberKind`]
+/// carries the same set of flags for a single member.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize, Serialize)]
+pub struct ChatPermissions {
+    ///True, if the user is allowed to send text messages, contacts, locations and venues
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_messages: Option<bool>,
-    ///Optional. Restricted only. True, if the user can send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages
+    ///True, if the user is allowed to send audios, documents, photos, videos, video notes and voice notes, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_media_messages: Option<bool>,
-    ///Optional. Restricted only. True, if the user can send animations, games, stickers and use inline bots, implies can_send_media_messages
+    ///True, if the user is allowed to send polls, implies can_send_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_send_polls: Option<bool>,
+    ///True, if the user is allowed to send animations, games, stickers and use inline bots, implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_send_other_messages: Option<bool>,
-    ///Optional. Restricted only. True, if user may add web page previews to his messages, implies can_send_media_messages
+    ///True, if the user is allowed to add web page previews to their messages, implies can_send_media_messages
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub can_add_web_page_previews: Option<bool>,
+    ///True, if the user is allowed to change the chat title, photo and other settings, ignored in public supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_change_info: Option<bool>,
+    ///True, if the user is allowed to invite new users to the chat
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_invite_users: Option<bool>,
+    ///True, if the user is allowed to pin messages, ignored in public supergroups
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_pin_messages: Option<bool>,
+}
+
+impl ChatMember {
+    /// True, if the member can send text messages, contacts, locations and venues.
+    ///
+    /// A plain `Member` falls back to the chat's `defaults`, since regular
+    /// members aren't guaranteed every permission themselves; a `Restricted`
+    /// member's own flag is still subject to being overridden by `defaults`.
+    pub fn effective_can_send_messages(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } | Administrator { .. } => true,
+            Member => defaults.can_send_messages.unwrap_or(true),
+            Restricted {
+                can_send_messages, ..
+            } => can_send_messages.unwrap_or(true) && defaults.can_send_messages.unwrap_or(true),
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
+
+    /// True, if the member can send audios, documents, photos, videos, video
+    /// notes and voice notes.
+    pub fn effective_can_send_media_messages(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } | Administrator { .. } => true,
+            Member => defaults.can_send_media_messages.unwrap_or(true),
+            Restricted {
+                can_send_media_messages,
+                ..
+            } => {
+                can_send_media_messages.unwrap_or(true)
+                    && defaults.can_send_media_messages.unwrap_or(true)
+            }
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
+
+    /// True, if the member can send polls.
+    pub fn effective_can_send_polls(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } | Administrator { .. } => true,
+            Member => defaults.can_send_polls.unwrap_or(true),
+            Restricted { can_send_polls, .. } => {
+                can_send_polls.unwrap_or(true) && defaults.can_send_polls.unwrap_or(true)
+            }
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
+
+    /// True, if the member can send animations, games, stickers and use inline bots.
+    pub fn effective_can_send_other_messages(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } | Administrator { .. } => true,
+            Member => defaults.can_send_other_messages.unwrap_or(true),
+            Restricted {
+                can_send_other_messages,
+                ..
+            } => {
+                can_send_other_messages.unwrap_or(true)
+                    && defaults.can_send_other_messages.unwrap_or(true)
+            }
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
+
+    /// True, if the member may add web page previews to their messages.
+    pub fn effective_can_add_web_page_previews(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } | Administrator { .. } => true,
+            Member => defaults.can_add_web_page_previews.unwrap_or(true),
+            Restricted {
+                can_add_web_page_previews,
+                ..
+            } => {
+                can_add_web_page_previews.unwrap_or(true)
+                    && defaults.can_add_web_page_previews.unwrap_or(true)
+            }
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
+
+    /// True, if the member can invite new users to the chat.
+    pub fn effective_can_invite_users(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } => true,
+            Administrator { can_invite_users, .. } => can_invite_users.unwrap_or(false),
+            Member => defaults.can_invite_users.unwrap_or(true),
+            Restricted {
+                can_invite_users, ..
+            } => can_invite_users.unwrap_or(true) && defaults.can_invite_users.unwrap_or(true),
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
+
+    /// True, if the member can change the chat title, photo and other settings.
+    pub fn effective_can_change_info(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } => true,
+            Administrator { can_change_info, .. } => can_change_info.unwrap_or(false),
+            Member => defaults.can_change_info.unwrap_or(true),
+            Restricted { can_change_info, .. } => {
+                can_change_info.unwrap_or(true) && defaults.can_change_info.unwrap_or(true)
+            }
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
+
+    /// True, if the member can pin messages, supergroups only.
+    pub fn effective_can_pin_messages(&self, defaults: &ChatPermissions) -> bool {
+        use self::ChatMemberKind::*;
+        match &self.kind {
+            Owner { .. } => true,
+            Administrator { can_pin_messages, .. } => can_pin_messages.unwrap_or(false),
+            Member => defaults.can_pin_messages.unwrap_or(true),
+            Restricted { can_pin_messages, .. } => {
+                can_pin_messages.unwrap_or(true) && defaults.can_pin_messages.unwrap_or(true)
+            }
+            Left | Banned { .. } | Unknown(_) => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Deserialize)]
@@ -122,3 +430,218 @@ pub struct ChatMemberUpdated {
     /// events only
     pub invite_link: Option<ChatInviteLink>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> User {
+        User {
+            id: UserId::new(42),
+            is_bot: false,
+            first_name: "Test".to_string(),
+            last_name: None,
+            username: None,
+            language_code: None,
+        }
+    }
+
+    fn member(kind_json: serde_json::Value) -> ChatMember {
+        let mut value = kind_json;
+        value["user"] = serde_json::json!({
+            "id": 42,
+            "is_bot": false,
+            "first_name": "Test",
+        });
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn deserializes_creator() {
+        let m = member(serde_json::json!({
+            "status": "creator",
+            "custom_title": "Boss",
+            "is_anonymous": true,
+        }));
+        match m.kind {
+            ChatMemberKind::Owner {
+                custom_title,
+                is_anonymous,
+            } => {
+                assert_eq!(custom_title.as_deref(), Some("Boss"));
+                assert_eq!(is_anonymous, Some(true));
+            }
+            other => panic!("expected Owner, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserializes_administrator() {
+        let m = member(serde_json::json!({
+            "status": "administrator",
+            "can_invite_users": true,
+            "can_pin_messages": false,
+        }));
+        match m.kind {
+            ChatMemberKind::Administrator {
+                can_invite_users,
+                can_pin_messages,
+                ..
+            } => {
+                assert_eq!(can_invite_users, Some(true));
+                assert_eq!(can_pin_messages, Some(false));
+            }
+            other => panic!("expected Administrator, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserializes_member() {
+        let m = member(serde_json::json!({"status": "member"}));
+        assert_eq!(m.kind, ChatMemberKind::Member);
+    }
+
+    #[test]
+    fn deserializes_restricted() {
+        let m = member(serde_json::json!({
+            "status": "restricted",
+            "until_date": 1234,
+            "can_send_messages": false,
+            "can_invite_users": true,
+        }));
+        match m.kind {
+            ChatMemberKind::Restricted {
+                until_date,
+                can_send_messages,
+                can_invite_users,
+                ..
+            } => {
+                assert_eq!(until_date, Some(1234));
+                assert_eq!(can_send_messages, Some(false));
+                assert_eq!(can_invite_users, Some(true));
+            }
+            other => panic!("expected Restricted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn deserializes_left() {
+        let m = member(serde_json::json!({"status": "left"}));
+        assert_eq!(m.kind, ChatMemberKind::Left);
+    }
+
+    #[test]
+    fn deserializes_kicked_as_banned() {
+        let m = member(serde_json::json!({"status": "kicked", "until_date": 5678}));
+        assert_eq!(
+            m.kind,
+            ChatMemberKind::Banned {
+                until_date: Some(5678)
+            }
+        );
+    }
+
+    #[test]
+    fn deserializes_unrecognized_status_keeping_the_raw_string() {
+        let m = member(serde_json::json!({"status": "emperor"}));
+        assert_eq!(m.kind, ChatMemberKind::Unknown("emperor".to_string()));
+    }
+
+    #[test]
+    fn restricted_own_flag_overrides_looser_defaults() {
+        let m = ChatMember {
+            user: user(),
+            kind: ChatMemberKind::Restricted {
+                until_date: None,
+                can_send_messages: Some(false),
+                can_send_media_messages: None,
+                can_send_polls: None,
+                can_send_other_messages: None,
+                can_add_web_page_previews: None,
+                can_invite_users: None,
+                can_change_info: None,
+                can_pin_messages: None,
+            },
+        };
+        let permissive_defaults = ChatPermissions {
+            can_send_messages: Some(true),
+            can_send_media_messages: Some(true),
+            can_send_polls: Some(true),
+            can_send_other_messages: Some(true),
+            can_add_web_page_previews: Some(true),
+            can_change_info: Some(true),
+            can_invite_users: Some(true),
+            can_pin_messages: Some(true),
+        };
+        assert!(!m.effective_can_send_messages(&permissive_defaults));
+    }
+
+    #[test]
+    fn restricted_inherits_stricter_defaults_even_if_own_flag_allows() {
+        let m = ChatMember {
+            user: user(),
+            kind: ChatMemberKind::Restricted {
+                until_date: None,
+                can_send_messages: Some(true),
+                can_send_media_messages: None,
+                can_send_polls: None,
+                can_send_other_messages: None,
+                can_add_web_page_previews: None,
+                can_invite_users: Some(true),
+                can_change_info: None,
+                can_pin_messages: None,
+            },
+        };
+        let restrictive_defaults = ChatPermissions {
+            can_send_messages: Some(false),
+            can_send_media_messages: Some(false),
+            can_send_polls: Some(false),
+            can_send_other_messages: Some(false),
+            can_add_web_page_previews: Some(false),
+            can_change_info: Some(false),
+            can_invite_users: Some(false),
+            can_pin_messages: Some(false),
+        };
+        assert!(!m.effective_can_send_messages(&restrictive_defaults));
+        assert!(!m.effective_can_invite_users(&restrictive_defaults));
+    }
+
+    #[test]
+    fn member_falls_back_to_defaults() {
+        let m = ChatMember {
+            user: user(),
+            kind: ChatMemberKind::Member,
+        };
+        let defaults = ChatPermissions {
+            can_send_messages: Some(false),
+            can_send_media_messages: None,
+            can_send_polls: None,
+            can_send_other_messages: None,
+            can_add_web_page_previews: None,
+            can_change_info: None,
+            can_invite_users: None,
+            can_pin_messages: None,
+        };
+        assert!(!m.effective_can_send_messages(&defaults));
+    }
+
+    #[test]
+    fn banned_can_never_do_anything() {
+        let m = ChatMember {
+            user: user(),
+            kind: ChatMemberKind::Banned { until_date: None },
+        };
+        let wide_open = ChatPermissions {
+            can_send_messages: Some(true),
+            can_send_media_messages: Some(true),
+            can_send_polls: Some(true),
+            can_send_other_messages: Some(true),
+            can_add_web_page_previews: Some(true),
+            can_change_info: Some(true),
+            can_invite_users: Some(true),
+            can_pin_messages: Some(true),
+        };
+        assert!(!m.effective_can_send_messages(&wide_open));
+        assert!(!m.effective_can_invite_users(&wide_open));
+    }
+}